@@ -0,0 +1,87 @@
+// Cross-platform clipboard integration.
+//
+// Copies text to the system clipboard, detecting the Linux session type and
+// preferring `wl-copy` under Wayland before falling back to `xclip`. Detection
+// degrades gracefully: a missing backend is logged at debug level and never
+// aborts the run.
+
+use log::debug;
+
+/// Copy `text` to the system clipboard.
+pub fn copy(text: &str) {
+    #[cfg(target_os = "windows")]
+    copy_windows(text);
+
+    #[cfg(target_os = "macos")]
+    copy_macos(text);
+
+    #[cfg(target_os = "linux")]
+    copy_linux(text);
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+    {
+        let _ = text;
+        debug!("no clipboard backend for this platform");
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn copy_windows(text: &str) {
+    use std::process::Command;
+    if Command::new("cmd")
+        .args(["/C", &format!("echo {} | clip", text)])
+        .output()
+        .is_err()
+    {
+        debug!("clip unavailable");
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn copy_macos(text: &str) {
+    if !pipe_to(text, "pbcopy", &[]) {
+        debug!("pbcopy unavailable");
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn copy_linux(text: &str) {
+    use std::env;
+
+    let wayland = env::var_os("WAYLAND_DISPLAY").is_some()
+        || env::var("XDG_SESSION_TYPE")
+            .map(|v| v.eq_ignore_ascii_case("wayland"))
+            .unwrap_or(false);
+
+    if wayland {
+        if pipe_to(text, "wl-copy", &[]) {
+            return;
+        }
+        debug!("wl-copy unavailable, falling back to xclip");
+    }
+
+    if !pipe_to(text, "xclip", &["-selection", "clipboard"]) {
+        debug!("no clipboard backend (wl-copy/xclip) available");
+    }
+}
+
+/// Spawn `cmd` and feed `text` to its stdin, returning whether it launched.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn pipe_to(text: &str, cmd: &str, args: &[&str]) -> bool {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    match Command::new(cmd).args(args).stdin(Stdio::piped()).spawn() {
+        Ok(mut child) => {
+            if let Some(mut stdin) = child.stdin.take() {
+                let _ = stdin.write_all(text.as_bytes());
+            }
+            let _ = child.wait();
+            true
+        }
+        Err(e) => {
+            debug!("failed to launch {}: {}", cmd, e);
+            false
+        }
+    }
+}
@@ -0,0 +1,169 @@
+// Minimal .gitignore matching for `--respect-gitignore`.
+//
+// For each target path we walk up its ancestor directories, parse any
+// `.gitignore` found, and apply the patterns relative to each file's location.
+// Supported syntax is the common subset: `*` and `?` wildcards, `**` spanning
+// directories, leading `/` anchoring, trailing `/` directory markers, `#`
+// comments, and `!` negation (last matching pattern wins).
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A single parsed `.gitignore` rule.
+struct Rule {
+    segments: Vec<String>,
+    negated: bool,
+    /// Set for a trailing `/`: the rule only matches directories.
+    dir_only: bool,
+}
+
+impl Rule {
+    /// Parse one line, returning `None` for blanks and comments.
+    fn parse(line: &str) -> Option<Rule> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let negated = line.starts_with('!');
+        let line = if negated { &line[1..] } else { line };
+        let dir_only = line.ends_with('/');
+        let line = line.trim_end_matches('/');
+        // A slash anywhere (other than trailing) anchors the pattern; otherwise
+        // it matches at any depth, modelled by a leading `**` segment.
+        let anchored = line.contains('/');
+        let line = line.trim_start_matches('/');
+
+        let mut segments: Vec<String> = line.split('/').map(String::from).collect();
+        if !anchored {
+            segments.insert(0, "**".to_string());
+        }
+
+        Some(Rule {
+            segments,
+            negated,
+            dir_only,
+        })
+    }
+
+    /// Does this rule match `segs`, or any ancestor prefix of it?
+    ///
+    /// Matching a prefix covers the "ignoring a directory ignores everything
+    /// inside it" rule without needing the tree to exist on disk yet. The last
+    /// segment is the file to be created, so a directory-only rule is tried
+    /// against the ancestor prefixes alone.
+    fn matches(&self, segs: &[&str]) -> bool {
+        let pat: Vec<&str> = self.segments.iter().map(String::as_str).collect();
+        let last = if self.dir_only { segs.len() - 1 } else { segs.len() };
+        (1..=last).any(|end| match_segments(&pat, &segs[..end]))
+    }
+}
+
+/// Caches parsed `.gitignore` files so repeated lookups stay cheap.
+pub struct IgnoreSet {
+    cache: HashMap<PathBuf, Vec<Rule>>,
+}
+
+impl Default for IgnoreSet {
+    fn default() -> Self {
+        IgnoreSet::new()
+    }
+}
+
+impl IgnoreSet {
+    /// Create an empty matcher.
+    pub fn new() -> Self {
+        IgnoreSet {
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Return whether `path` would be ignored by a `.gitignore` above it.
+    pub fn is_ignored(&mut self, path: &str) -> bool {
+        let abs = absolutize(Path::new(path));
+        let dir = abs.parent().map(Path::to_path_buf).unwrap_or_else(|| abs.clone());
+
+        // Evaluate ancestors from the top down so deeper files override.
+        let mut ancestors: Vec<PathBuf> = dir.ancestors().map(Path::to_path_buf).collect();
+        ancestors.reverse();
+
+        let mut ignored = false;
+        for base in ancestors {
+            let rel = match abs.strip_prefix(&base) {
+                Ok(rel) => rel,
+                Err(_) => continue,
+            };
+            let segs: Vec<&str> = rel
+                .components()
+                .filter_map(|c| c.as_os_str().to_str())
+                .collect();
+            if segs.is_empty() {
+                continue;
+            }
+
+            for rule in self.rules_for(&base) {
+                if rule.matches(&segs) {
+                    ignored = !rule.negated;
+                }
+            }
+        }
+
+        ignored
+    }
+
+    /// Parse (and cache) the rules from `<base>/.gitignore`.
+    fn rules_for(&mut self, base: &Path) -> &[Rule] {
+        self.cache.entry(base.to_path_buf()).or_insert_with(|| {
+            match fs::read_to_string(base.join(".gitignore")) {
+                Ok(content) => content.lines().filter_map(Rule::parse).collect(),
+                Err(_) => Vec::new(),
+            }
+        })
+    }
+}
+
+/// Resolve `path` against the current directory without requiring it to exist.
+fn absolutize(path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        env::current_dir()
+            .unwrap_or_else(|_| PathBuf::from("."))
+            .join(path)
+    }
+}
+
+/// Match glob `pat` segments against `text` segments, with `**` spanning any
+/// number of segments.
+fn match_segments(pat: &[&str], text: &[&str]) -> bool {
+    if pat.is_empty() {
+        return text.is_empty();
+    }
+    if pat[0] == "**" {
+        return (0..=text.len()).any(|i| match_segments(&pat[1..], &text[i..]));
+    }
+    if text.is_empty() {
+        return false;
+    }
+    wildcard_match(pat[0], text[0]) && match_segments(&pat[1..], &text[1..])
+}
+
+/// Match a single path segment, honoring `*` (within a segment) and `?`.
+fn wildcard_match(pat: &str, text: &str) -> bool {
+    let p: Vec<char> = pat.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    wm(&p, &t)
+}
+
+fn wm(p: &[char], t: &[char]) -> bool {
+    if p.is_empty() {
+        return t.is_empty();
+    }
+    match p[0] {
+        '*' => (0..=t.len()).any(|i| wm(&p[1..], &t[i..])),
+        '?' => !t.is_empty() && wm(&p[1..], &t[1..]),
+        c => !t.is_empty() && t[0] == c && wm(&p[1..], &t[1..]),
+    }
+}
@@ -0,0 +1,81 @@
+// Lightweight terminal logger for mkfile.
+//
+// Routes every diagnostic through the `log` facade so verbosity is uniform
+// and machine-parseable. Records go to stderr and, optionally, a mirror file,
+// which keeps them on a separate stream from the ✓/✗ summary lines mkfile
+// prints to stdout.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+use log::{LevelFilter, Metadata, Record};
+
+/// Terminal logger writing to stderr, optionally mirrored to a file.
+struct TermLogger {
+    level: LevelFilter,
+    file: Option<Mutex<std::fs::File>>,
+}
+
+impl log::Log for TermLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let line = format!("[{}] {}", record.level(), record.args());
+        eprintln!("{}", line);
+
+        if let Some(file) = &self.file {
+            if let Ok(mut f) = file.lock() {
+                let _ = writeln!(f, "{}", line);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(file) = &self.file {
+            if let Ok(mut f) = file.lock() {
+                let _ = f.flush();
+            }
+        }
+    }
+}
+
+/// Install the global logger.
+///
+/// `verbosity` counts `-v` occurrences (0 = warnings only, 1 = info,
+/// 2 = debug, 3+ = trace) while `quiet` collapses output to errors only.
+/// When `log_file` is given every record is also appended to that path.
+pub fn init(verbosity: u8, quiet: bool, log_file: Option<&Path>) {
+    let level = if quiet {
+        LevelFilter::Error
+    } else {
+        match verbosity {
+            0 => LevelFilter::Warn,
+            1 => LevelFilter::Info,
+            2 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        }
+    };
+
+    let file = log_file.and_then(|p| {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(p)
+            .map(Mutex::new)
+            .ok()
+    });
+
+    let logger = TermLogger { level, file };
+    // Ignore the error if a logger was already installed.
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        log::set_max_level(level);
+    }
+}
@@ -4,16 +4,28 @@
 // Author: cumulus13 (cumulus13@gmail.com)
 // Version: 2.0
 
-use regex::Regex;
 use std::env;
 use std::fs::{self, File};
+use std::io::{self, IsTerminal, Write};
 use std::path::{Path, PathBuf};
 use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Mutex;
+use std::thread;
+use crossbeam_channel::unbounded;
 use lazy_static::lazy_static;
+use log::{debug, error, info, warn};
 
 use gntp::{GntpClient, NotificationType, Resource};
 
+mod clipboard;
+mod gitignore;
+mod logger;
+mod templates;
+
+use gitignore::IgnoreSet;
+use templates::TemplateSet;
+
 const NAME: &str = "mkfile";
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const AUTHOR: &str = "cumulus13 (cumulus13@gmail.com)";
@@ -23,16 +35,33 @@ lazy_static! {
     static ref GNTP_CLIENT: Mutex<Option<GntpClient>> = Mutex::new(None);
 }
 
+/// Progress event emitted by a worker as each file is processed.
+struct Progress {
+    total: usize,
+    path: String,
+    ok: bool,
+}
+
 /// FileCreator handles file creation with notification support
 struct FileCreator {
     icon_path: PathBuf,
-    debug: bool,
     use_gntp: bool,
+    auto_sep: bool,
+    blank: bool,
+    use_clipboard: bool,
+    template: Option<String>,
+    templates: TemplateSet,
 }
 
 impl FileCreator {
     /// Creates a new FileCreator instance
-    fn new(debug: bool, use_gntp: bool) -> Self {
+    fn new(
+        use_gntp: bool,
+        auto_sep: bool,
+        blank: bool,
+        use_clipboard: bool,
+        template: Option<String>,
+    ) -> Self {
         let exe_path = env::current_exe().unwrap_or_else(|_| PathBuf::from("."));
         let icon_path = exe_path
             .parent()
@@ -41,25 +70,29 @@ impl FileCreator {
 
         FileCreator {
             icon_path,
-            debug,
             use_gntp,
+            auto_sep,
+            blank,
+            use_clipboard,
+            template,
+            templates: TemplateSet::load(),
         }
     }
 
     /// Initialize GNTP client (call once)
-    fn init_gntp(&self) -> Result<(), String> {
+    fn init_gntp(&self) {
         if !self.use_gntp {
-            return Ok(());
+            return;
         }
 
         let mut client_guard = GNTP_CLIENT.lock().unwrap();
-        
+
         if client_guard.is_some() {
-            return Ok(()); // Already initialized
+            return; // Already initialized
         }
 
         let mut client = GntpClient::new(NAME);
-        
+
         // Load application icon if exists
         if self.icon_path.exists() {
             match Resource::from_file(&self.icon_path) {
@@ -67,9 +100,7 @@ impl FileCreator {
                     client = client.with_icon(icon);
                 }
                 Err(e) => {
-                    if self.debug {
-                        eprintln!("Warning: Could not load icon: {:?}", e);
-                    }
+                    warn!("Could not load icon: {:?}", e);
                 }
             }
         }
@@ -82,78 +113,65 @@ impl FileCreator {
         match client.register(vec![notification]) {
             Ok(_) => {
                 *client_guard = Some(client);
-                Ok(())
-            }
-            Err(e) => {
-                if self.debug {
-                    Err(format!("GNTP registration failed: {:?}", e))
-                } else {
-                    // Silent fail if GNTP not available
-                    Ok(())
-                }
+                debug!("GNTP client registered");
             }
+            // Degrade gracefully when no GNTP server is reachable.
+            Err(e) => warn!("GNTP registration failed: {:?}", e),
         }
     }
 
-    /// Parse brace expansion patterns
+    /// Expand Bash-style brace patterns into concrete paths.
+    ///
+    /// Delegates to [`expand_braces`], passing the creator's `auto_sep`
+    /// setting so the legacy `/`-injection behaviour stays opt-in.
     fn parse_brace_expansion(&self, text: &str) -> Vec<String> {
-        let pattern = Regex::new(r"([^{]*)\{([^}]+)\}([^{]*)").unwrap();
-
-        if let Some(caps) = pattern.captures(text) {
-            let mut prefix = caps.get(1).map_or("", |m| m.as_str()).to_string();
-            let items_str = caps.get(2).map_or("", |m| m.as_str());
-            let suffix = caps.get(3).map_or("", |m| m.as_str());
-
-            // Add separator if needed
-            if !prefix.is_empty() && !prefix.ends_with('/') && !prefix.ends_with('\\') {
-                prefix.push('/');
-            }
-
-            // Split and process items
-            let parts: Vec<&str> = items_str.split(',').collect();
-            let mut items = Vec::new();
-
-            for part in parts {
-                let sub_items: Vec<&str> = part.trim().split_whitespace().collect();
-                items.extend(sub_items);
-            }
-
-            // Expand into individual files
-            let mut expanded = Vec::new();
-            for item in items {
-                if !item.is_empty() {
-                    let filepath = format!("{}{}{}", prefix, item, suffix);
-                    expanded.push(filepath);
-                }
-            }
-
-            expanded
-        } else {
-            vec![text.to_string()]
-        }
+        expand_braces(text, self.auto_sep)
     }
 
-    /// Create a blank file
-    fn create_file(&self, filepath: &str) -> bool {
+    /// Create a file, seeded with template content unless `blank` is set,
+    /// without emitting the user-facing summary line.
+    ///
+    /// Returns whether creation succeeded together with the path to report:
+    /// the canonical absolute path on success (or the path as given if it
+    /// cannot be canonicalized), or the original argument on
+    /// failure. Printing is left to the single printer thread so that output
+    /// from concurrent workers never interleaves.
+    fn create_file(&self, filepath: &str) -> (bool, String) {
         let path = Path::new(filepath);
 
         // Create parent directories if needed
         if let Some(parent) = path.parent() {
-            if let Err(e) = fs::create_dir_all(parent) {
-                eprintln!("✗ Error creating directory for \"{}\": {}", filepath, e);
-                return false;
+            if !parent.as_os_str().is_empty() {
+                if let Err(e) = fs::create_dir_all(parent) {
+                    error!("Error creating directory for \"{}\": {}", filepath, e);
+                    return (false, filepath.to_string());
+                }
             }
         }
 
-        // Create the file
+        // Resolve starter content unless a blank file was requested.
+        let content = if self.blank {
+            None
+        } else {
+            self.templates.content_for(filepath, self.template.as_deref())
+        };
+
+        // Create the file, seeding it with any template content.
         match File::create(path) {
-            Ok(_) => {}
-            Err(e) => {
-                eprintln!("✗ Error creating file \"{}\": {}", filepath, e);
-                if self.debug {
-                    eprintln!("   Debug: {:?}", e);
+            Ok(mut file) => {
+                if let Some(content) = content {
+                    if !content.is_empty() {
+                        if let Err(e) = file.write_all(content.as_bytes()) {
+                            error!("Error writing template for \"{}\": {}", filepath, e);
+                            return (false, filepath.to_string());
+                        }
+                    }
                 }
-                return false;
+            }
+            Err(e) => {
+                error!("Error creating file \"{}\": {}", filepath, e);
+                debug!("create_file error detail for \"{}\": {:?}", filepath, e);
+                return (false, filepath.to_string());
             }
         }
 
@@ -164,52 +182,10 @@ impl FileCreator {
             .display()
             .to_string();
 
-        // Copy to clipboard (platform specific)
-        self.copy_to_clipboard(&abs_path);
-
         // Send notification
         self.notify(filepath);
 
-        println!("✓ File created: \"{}\"", abs_path);
-        true
-    }
-
-    /// Copy text to clipboard (platform specific)
-    fn copy_to_clipboard(&self, text: &str) {
-        #[cfg(target_os = "windows")]
-        {
-            use std::process::Command;
-            let _ = Command::new("cmd")
-                .args(&["/C", &format!("echo {} | clip", text)])
-                .output();
-        }
-
-        #[cfg(target_os = "macos")]
-        {
-            use std::process::Command;
-            use std::io::Write;
-            if let Ok(mut child) = Command::new("pbcopy")
-                .stdin(std::process::Stdio::piped())
-                .spawn() {
-                if let Some(mut stdin) = child.stdin.take() {
-                    let _ = stdin.write_all(text.as_bytes());
-                }
-            }
-        }
-
-        #[cfg(target_os = "linux")]
-        {
-            use std::process::Command;
-            use std::io::Write;
-            if let Ok(mut child) = Command::new("xclip")
-                .args(&["-selection", "clipboard"])
-                .stdin(std::process::Stdio::piped())
-                .spawn() {
-                if let Some(mut stdin) = child.stdin.take() {
-                    let _ = stdin.write_all(text.as_bytes());
-                }
-            }
-        }
+        (true, abs_path)
     }
 
     /// Send notification
@@ -231,40 +207,310 @@ impl FileCreator {
                 NAME,
                 &format!("File created: \"{}\"", filename)
             ) {
-                Ok(_) => {},
-                Err(e) => {
-                    if self.debug {
-                        eprintln!("Notification error: {:?}", e);
-                    }
-                }
+                Ok(_) => {}
+                Err(e) => warn!("Notification failed: {:?}", e),
             }
         }
     }
 
-    /// Create multiple files
-    fn create_files(&self, files: &[String]) -> usize {
-        let mut success_count = 0;
+    /// Expand every brace pattern in `files` into a flat list of paths.
+    fn expand(&self, files: &[String]) -> Vec<String> {
         let mut all_files = Vec::new();
-
-        // Expand all brace patterns
         for file_arg in files {
             if file_arg.contains('{') && file_arg.contains('}') {
-                let expanded = self.parse_brace_expansion(file_arg);
-                all_files.extend(expanded);
+                all_files.extend(self.parse_brace_expansion(file_arg));
             } else {
                 all_files.push(file_arg.clone());
             }
         }
+        all_files
+    }
+
+    /// Create the already-expanded `all_files` across a pool of `jobs` workers.
+    ///
+    /// The paths are dispatched over a crossbeam work channel. Workers share an
+    /// [`AtomicUsize`] success counter and forward a [`Progress`] event per
+    /// file to a single printer thread. With `progress` set and stdout on a
+    /// TTY the printer collapses output to one updating `created X/Y` line;
+    /// otherwise it falls back to the per-file summary lines.
+    fn create_files(&self, all_files: Vec<String>, jobs: usize, progress: bool) -> usize {
+        let total = all_files.len();
+        if total == 0 {
+            return 0;
+        }
+
+        let jobs = jobs.clamp(1, total);
+        info!("creating {} file(s) with {} worker(s)", total, jobs);
+        let success = AtomicUsize::new(0);
+        // Absolute paths of every file that was created successfully, gathered
+        // so the clipboard can be populated once at the end of the run.
+        let created: Mutex<Vec<String>> = Mutex::new(Vec::new());
+        let (work_tx, work_rx) = unbounded::<String>();
+        let (prog_tx, prog_rx) = unbounded::<Progress>();
 
-        // Create all files
         for filepath in all_files {
-            if self.create_file(&filepath) {
-                success_count += 1;
+            work_tx.send(filepath).expect("work channel closed");
+        }
+        drop(work_tx);
+
+        let progress_line = progress && io::stdout().is_terminal();
+
+        thread::scope(|scope| {
+            // Single printer thread keeps concurrent output ordered.
+            scope.spawn(move || {
+                let mut ok_count = 0usize;
+                for ev in prog_rx.iter() {
+                    if ev.ok {
+                        ok_count += 1;
+                    }
+                    if progress_line {
+                        print!("\rcreated {}/{}", ok_count, ev.total);
+                        let _ = io::stdout().flush();
+                    } else if ev.ok {
+                        println!("✓ File created: \"{}\"", ev.path);
+                    } else {
+                        println!("✗ Failed: \"{}\"", ev.path);
+                    }
+                }
+                if progress_line {
+                    println!();
+                }
+            });
+
+            // Worker pool draining the shared work channel.
+            let success = &success;
+            let created = &created;
+            for _ in 0..jobs {
+                let work_rx = work_rx.clone();
+                let prog_tx = prog_tx.clone();
+                scope.spawn(move || {
+                    while let Ok(path) = work_rx.recv() {
+                        let (ok, shown) = self.create_file(&path);
+                        if ok {
+                            success.fetch_add(1, Ordering::Relaxed);
+                            created.lock().unwrap().push(shown.clone());
+                        }
+                        let _ = prog_tx.send(Progress {
+                            total,
+                            path: shown,
+                            ok,
+                        });
+                    }
+                });
+            }
+            drop(prog_tx);
+            drop(work_rx);
+        });
+
+        // Copy the full list of created paths to the clipboard in one shot.
+        if self.use_clipboard {
+            let created = created.into_inner().unwrap();
+            if !created.is_empty() {
+                clipboard::copy(&created.join("\n"));
+            }
+        }
+
+        success.load(Ordering::Relaxed)
+    }
+}
+
+/// Recursively expand Bash-style brace groups in `text`.
+///
+/// Scans for the first top-level `{`, finds its matching `}` by tracking
+/// brace depth, and splits the body on top-level commas. A body shaped like
+/// `START..END[..STEP]` becomes an inclusive numeric or character sequence;
+/// otherwise each comma segment is a literal alternative. Every alternative is
+/// substituted back into `prefix + alt + suffix` and re-expanded, so nested
+/// braces and multiple groups multiply out as a cartesian product. A body that
+/// is neither a range nor a comma list is left literal, matching Bash.
+///
+/// When `auto_sep` is set the historical behaviour of inserting a `/` between a
+/// comma-list group and a non-separated prefix is preserved; numeric and
+/// character sequences never inject separators.
+fn expand_braces(text: &str, auto_sep: bool) -> Vec<String> {
+    let open = match text.find('{') {
+        Some(i) => i,
+        None => return vec![text.to_string()],
+    };
+    let close = match matching_brace(text, open) {
+        Some(i) => i,
+        None => return vec![text.to_string()],
+    };
+
+    let prefix = &text[..open];
+    let body = &text[open + 1..close];
+    let suffix = &text[close + 1..];
+
+    let (alternatives, is_range) = if let Some(seq) = expand_range(body) {
+        (seq, true)
+    } else {
+        let parts = split_top_level_commas(body);
+        if parts.len() > 1 {
+            (parts.into_iter().map(|s| s.to_string()).collect(), false)
+        } else {
+            // Not a real expansion: keep `{body}` literal and continue with
+            // any remaining groups in the suffix.
+            let head = format!("{}{{{}}}", prefix, body);
+            return expand_braces(suffix, auto_sep)
+                .into_iter()
+                .map(|rest| format!("{}{}", head, rest))
+                .collect();
+        }
+    };
+
+    let sep = if auto_sep
+        && !is_range
+        && !prefix.is_empty()
+        && !prefix.ends_with('/')
+        && !prefix.ends_with('\\')
+    {
+        "/"
+    } else {
+        ""
+    };
+
+    let mut expanded = Vec::new();
+    for alt in alternatives {
+        let candidate = format!("{}{}{}{}", prefix, sep, alt, suffix);
+        expanded.extend(expand_braces(&candidate, auto_sep));
+    }
+    expanded
+}
+
+/// Return the byte index of the `}` matching the `{` at `open`.
+fn matching_brace(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, ch) in text.char_indices().filter(|(i, _)| *i >= open) {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
             }
+            _ => {}
         }
+    }
+    None
+}
 
-        success_count
+/// Split `body` on commas that sit at brace depth zero.
+fn split_top_level_commas(body: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0usize;
+    for (i, ch) in body.char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&body[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&body[start..]);
+    parts
+}
+
+/// Expand a `START..END[..STEP]` sequence body, or `None` if `body` is not one.
+fn expand_range(body: &str) -> Option<Vec<String>> {
+    let parts: Vec<&str> = body.split("..").collect();
+    if parts.len() != 2 && parts.len() != 3 {
+        return None;
+    }
+    let (start, end) = (parts[0], parts[1]);
+    let step_str = parts.get(2).copied();
+
+    // Numeric sequence, preserving zero-pad width when requested.
+    if let (Ok(s), Ok(e)) = (start.parse::<i64>(), end.parse::<i64>()) {
+        let step = match step_str {
+            Some(st) => st.parse::<i64>().ok()?.abs(),
+            None => 1,
+        };
+        if step == 0 {
+            return None;
+        }
+        let width = zero_pad_width(start, end);
+        let mut out = Vec::new();
+        let mut v = s;
+        if s <= e {
+            while v <= e {
+                out.push(fmt_num(v, width));
+                v += step;
+            }
+        } else {
+            while v >= e {
+                out.push(fmt_num(v, width));
+                v -= step;
+            }
+        }
+        return Some(out);
     }
+
+    // Single-character range (optionally stepped).
+    let sc: Vec<char> = start.chars().collect();
+    let ec: Vec<char> = end.chars().collect();
+    if sc.len() == 1 && ec.len() == 1 && sc[0].is_ascii_alphanumeric() && ec[0].is_ascii_alphanumeric() {
+        let step = match step_str {
+            Some(st) => st.parse::<u32>().ok()?.max(1),
+            None => 1,
+        };
+        let (s, e) = (sc[0] as u32, ec[0] as u32);
+        let mut out = Vec::new();
+        let mut c = s;
+        if s <= e {
+            while c <= e {
+                out.push(char::from_u32(c)?.to_string());
+                c += step;
+            }
+        } else {
+            loop {
+                out.push(char::from_u32(c)?.to_string());
+                if c < e + step {
+                    break;
+                }
+                c -= step;
+            }
+        }
+        return Some(out);
+    }
+
+    None
+}
+
+/// Determine the zero-pad width for a numeric range, or `0` if not padded.
+fn zero_pad_width(start: &str, end: &str) -> usize {
+    let padded = |s: &str| {
+        let t = s.strip_prefix('-').unwrap_or(s);
+        t.len() > 1 && t.starts_with('0')
+    };
+    if padded(start) || padded(end) {
+        let digits = |s: &str| s.strip_prefix('-').unwrap_or(s).len();
+        digits(start).max(digits(end))
+    } else {
+        0
+    }
+}
+
+/// Format an integer, left-padding with zeros to `width` when `width > 0`.
+fn fmt_num(v: i64, width: usize) -> String {
+    if width == 0 {
+        v.to_string()
+    } else if v < 0 {
+        format!("-{:0>width$}", v.abs(), width = width)
+    } else {
+        format!("{:0>width$}", v, width = width)
+    }
+}
+
+/// Default worker count: the number of logical CPUs, or 1 if undetectable.
+fn default_jobs() -> usize {
+    thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
 }
 
 /// Reconstruct file arguments
@@ -273,26 +519,22 @@ fn reconstruct_files(args: &[String]) -> Vec<String> {
 
     let mut file_list = Vec::new();
     let mut current = String::new();
-    let mut in_braces = false;
+    let mut depth = 0usize;
 
     for ch in joined.chars() {
         match ch {
             '{' => {
-                in_braces = true;
+                depth += 1;
                 current.push(ch);
             }
             '}' => {
-                in_braces = false;
+                depth = depth.saturating_sub(1);
                 current.push(ch);
             }
-            ' ' => {
-                if !in_braces {
-                    if !current.is_empty() {
-                        file_list.push(current.clone());
-                        current.clear();
-                    }
-                } else {
-                    current.push(ch);
+            ' ' if depth == 0 => {
+                if !current.is_empty() {
+                    file_list.push(current.clone());
+                    current.clear();
                 }
             }
             _ => {
@@ -314,14 +556,27 @@ fn print_help() {
     println!("Usage: mkfile [OPTIONS] FILE...\n");
     println!("Options:");
     println!("  --help, -h         Show this help message");
-    println!("  --version, -v      Show version information");
-    println!("  --debug, -d        Show detailed error messages");
-    println!("  --no-gntp          Disable GNTP/Growl notifications\n");
+    println!("  --version, -V      Show version information");
+    println!("  --verbose, -v      Increase verbosity (-v info, -vv debug, -vvv trace)");
+    println!("  --quiet            Only report errors");
+    println!("  --log-file PATH    Mirror log output to PATH");
+    println!("  --debug, -d        Alias for -vv (detailed diagnostics)");
+    println!("  --template NAME    Use a named template instead of auto-matching");
+    println!("  --blank            Force an empty file (skip templates)");
+    println!("  --no-gntp          Disable GNTP/Growl notifications");
+    println!("  --no-clipboard     Do not copy created paths to the clipboard");
+    println!("  --auto-sep         Insert a '/' between a brace group and its prefix");
+    println!("  --jobs N           Number of worker threads (default: CPU count)");
+    println!("  --progress         Render a single updating 'created X/Y' line");
+    println!("  --dry-run          List what would be created without touching disk");
+    println!("  --respect-gitignore  Skip paths matched by a .gitignore\n");
     println!("Examples:");
     println!("  mkfile file.txt                       # Create single file");
     println!("  mkfile file1.txt file2.py file3       # Create multiple files");
     println!("  mkfile dir/subdir/file.txt            # Create with directories");
     println!("  mkfile dir/{{a,b,c}}.txt                # Brace expansion");
+    println!("  mkfile file{{1..10}}.txt                # Numeric sequence");
+    println!("  mkfile src/{{a,b}}/{{x,y}}.rs             # Cartesian product");
     println!("  mkfile dotenv/{{__init__.py,core.py}}   # Create package structure");
     println!("\nNote: GNTP notifications require Growl for Windows or compatible client.");
 }
@@ -330,30 +585,103 @@ fn main() {
     let args: Vec<String> = env::args().skip(1).collect();
 
     // Parse flags
-    let mut debug = false;
+    let mut verbosity: u8 = 0;
+    let mut quiet = false;
+    let mut log_file: Option<PathBuf> = None;
     let mut use_gntp = true;
+    let mut use_clipboard = true;
+    let mut auto_sep = false;
+    let mut jobs = default_jobs();
+    let mut progress = false;
+    let mut blank = false;
+    let mut template: Option<String> = None;
+    let mut dry_run = false;
+    let mut respect_gitignore = false;
     let mut files = Vec::new();
 
-    for arg in &args {
-        match arg.as_str() {
+    let mut i = 0;
+    while i < args.len() {
+        let arg = args[i].as_str();
+        match arg {
             "--help" | "-h" => {
                 print_help();
                 process::exit(0);
             }
-            "--version" | "-v" => {
+            "--version" | "-V" => {
                 println!("mkfile v{} by {}", VERSION, AUTHOR);
                 process::exit(0);
             }
+            "--verbose" | "-v" => {
+                verbosity = verbosity.saturating_add(1);
+            }
+            "-vv" => {
+                verbosity = verbosity.saturating_add(2);
+            }
+            "-vvv" => {
+                verbosity = verbosity.saturating_add(3);
+            }
+            "--quiet" => {
+                quiet = true;
+            }
+            "--log-file" => {
+                i += 1;
+                match args.get(i) {
+                    Some(p) => log_file = Some(PathBuf::from(p)),
+                    None => {
+                        eprintln!("✗ --log-file requires a path");
+                        process::exit(2);
+                    }
+                }
+            }
             "--debug" | "-d" => {
-                debug = true;
+                verbosity = verbosity.max(2);
             }
             "--no-gntp" => {
                 use_gntp = false;
             }
+            "--no-clipboard" => {
+                use_clipboard = false;
+            }
+            "--auto-sep" => {
+                auto_sep = true;
+            }
+            "--jobs" | "-j" => {
+                i += 1;
+                match args.get(i).and_then(|v| v.parse::<usize>().ok()) {
+                    Some(n) if n >= 1 => jobs = n,
+                    _ => {
+                        eprintln!("✗ --jobs requires a positive integer");
+                        process::exit(2);
+                    }
+                }
+            }
+            "--progress" => {
+                progress = true;
+            }
+            "--blank" => {
+                blank = true;
+            }
+            "--dry-run" => {
+                dry_run = true;
+            }
+            "--respect-gitignore" => {
+                respect_gitignore = true;
+            }
+            "--template" => {
+                i += 1;
+                match args.get(i) {
+                    Some(name) => template = Some(name.clone()),
+                    None => {
+                        eprintln!("✗ --template requires a name");
+                        process::exit(2);
+                    }
+                }
+            }
             _ => {
-                files.push(arg.clone());
+                files.push(arg.to_string());
             }
         }
+        i += 1;
     }
 
     if files.is_empty() {
@@ -361,34 +689,73 @@ fn main() {
         process::exit(0);
     }
 
+    // Initialize logging before anything emits diagnostics.
+    logger::init(verbosity, quiet, log_file.as_deref());
+
     let file_list = reconstruct_files(&files);
 
     // Create FileCreator
-    let creator = FileCreator::new(debug, use_gntp);
-    
-    // Initialize GNTP
-    if let Err(e) = creator.init_gntp() {
-        if debug {
-            eprintln!("GNTP init error: {}", e);
+    let creator = FileCreator::new(use_gntp, auto_sep, blank, use_clipboard, template);
+
+    // Expand brace patterns, then optionally drop .gitignore'd paths.
+    let expanded = creator.expand(&file_list);
+    let (targets, skipped) = if respect_gitignore {
+        let mut ignore = IgnoreSet::new();
+        let mut kept = Vec::new();
+        let mut skipped = 0usize;
+        for path in expanded {
+            if ignore.is_ignored(&path) {
+                warn!("skipping ignored path \"{}\"", path);
+                skipped += 1;
+            } else {
+                kept.push(path);
+            }
+        }
+        (kept, skipped)
+    } else {
+        (expanded, 0)
+    };
+
+    let total_expected = targets.len();
+
+    // Dry-run: report the plan and exit without touching the filesystem.
+    if dry_run {
+        let mut dirs: Vec<String> = Vec::new();
+        for path in &targets {
+            if let Some(parent) = Path::new(path).parent() {
+                if !parent.as_os_str().is_empty() {
+                    let parent = parent.display().to_string();
+                    if !dirs.contains(&parent) {
+                        dirs.push(parent);
+                    }
+                }
+            }
+        }
+        for dir in &dirs {
+            println!("dir  {}", dir);
+        }
+        for path in &targets {
+            println!("file {}", path);
         }
+        print!("\nwould create {}", total_expected);
+        if skipped > 0 {
+            print!(", skipped {} (ignored)", skipped);
+        }
+        println!();
+        process::exit(0);
     }
 
+    // Initialize GNTP only once we know we are actually creating files.
+    creator.init_gntp();
+
     // Create files
-    let success_count = creator.create_files(&file_list);
-
-    // Calculate total expected
-    let total_expected: usize = file_list
-        .iter()
-        .map(|f| {
-            if f.contains('{') && f.contains('}') {
-                creator.parse_brace_expansion(f).len()
-            } else {
-                1
-            }
-        })
-        .sum();
+    let success_count = creator.create_files(targets, jobs, progress);
 
-    println!("\n{}/{} file(s) created successfully", success_count, total_expected);
+    print!("\n{}/{} file(s) created successfully", success_count, total_expected);
+    if skipped > 0 {
+        print!(", skipped {} (ignored)", skipped);
+    }
+    println!();
 
     if success_count == total_expected {
         process::exit(0);
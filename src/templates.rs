@@ -0,0 +1,106 @@
+// Extension-aware content templates.
+//
+// mkfile can seed a new file with sensible starter content instead of always
+// producing a blank file. Built-in templates live in a small table keyed by a
+// short name; users may override or extend them by dropping files into the
+// per-user config directory (one file per template, named after the template).
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use directories::ProjectDirs;
+use log::{debug, warn};
+
+/// Built-in starter templates keyed by a short name.
+fn builtins() -> HashMap<&'static str, &'static str> {
+    let mut m = HashMap::new();
+    m.insert(
+        "py",
+        "#!/usr/bin/env python3\n\n\ndef main():\n    pass\n\n\nif __name__ == \"__main__\":\n    main()\n",
+    );
+    m.insert("sh", "#!/usr/bin/env bash\nset -euo pipefail\n\n");
+    m.insert(
+        "html",
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n    <meta charset=\"utf-8\">\n    <title></title>\n</head>\n<body>\n</body>\n</html>\n",
+    );
+    // Package marker files stay empty on purpose.
+    m.insert("init", "");
+    m
+}
+
+/// Resolved set of templates: the built-in table merged with user overrides.
+pub struct TemplateSet {
+    templates: HashMap<String, String>,
+}
+
+impl TemplateSet {
+    /// Load the built-in templates and merge any user overrides.
+    ///
+    /// Overrides are read from `<config_dir>/templates/`, with each file's stem
+    /// used as the template name, so a `templates/py` file replaces the
+    /// built-in Python template.
+    pub fn load() -> Self {
+        let mut templates: HashMap<String, String> = builtins()
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+
+        if let Some(dir) = config_template_dir() {
+            match fs::read_dir(&dir) {
+                Ok(entries) => {
+                    for entry in entries.flatten() {
+                        let path = entry.path();
+                        let stem = path.file_stem().and_then(|s| s.to_str()).map(str::to_string);
+                        if let (Some(stem), Ok(content)) = (stem, fs::read_to_string(&path)) {
+                            debug!("loaded user template \"{}\"", stem);
+                            templates.insert(stem, content);
+                        }
+                    }
+                }
+                Err(e) => debug!("no user templates in {}: {}", dir.display(), e),
+            }
+        }
+
+        TemplateSet { templates }
+    }
+
+    /// Resolve the starter content for `filepath`.
+    ///
+    /// An explicit template `name` wins; otherwise the template is auto-matched
+    /// from the file name/extension. Returns the content to write, or `None`
+    /// when no template applies and the file should be left blank.
+    pub fn content_for(&self, filepath: &str, name: Option<&str>) -> Option<&str> {
+        if let Some(name) = name {
+            return match self.templates.get(name) {
+                Some(content) => Some(content.as_str()),
+                None => {
+                    warn!("unknown template \"{}\", creating blank file", name);
+                    None
+                }
+            };
+        }
+
+        let key = match_key(filepath)?;
+        self.templates.get(key).map(String::as_str)
+    }
+}
+
+/// Map a file name to a built-in template key.
+fn match_key(filepath: &str) -> Option<&'static str> {
+    let name = Path::new(filepath).file_name().and_then(|n| n.to_str())?;
+    if name == "__init__.py" {
+        return Some("init");
+    }
+    match Path::new(name).extension().and_then(|e| e.to_str())? {
+        "py" => Some("py"),
+        "sh" | "bash" => Some("sh"),
+        "html" | "htm" => Some("html"),
+        _ => None,
+    }
+}
+
+/// The per-user template directory, resolved ProjectDirs-style.
+fn config_template_dir() -> Option<PathBuf> {
+    ProjectDirs::from("", "cumulus13", "mkfile").map(|d| d.config_dir().join("templates"))
+}